@@ -1,4 +1,115 @@
-use jni::{errors::Result, objects::JObject, JNIEnv};
+use std::fmt;
+
+use jni::{
+    errors::Error as JniError,
+    objects::{JObject, JValue},
+    JNIEnv,
+};
+
+/// Errors surfaced by [`Intent`], [`IntentBuilder`], [`ActivityResult`], and [`TaskStack`]
+/// methods. A Java exception thrown by a Java method call is identified by class and message
+/// instead of surfacing as an opaque [`jni::errors::Error::JavaException`]; anything else (a bad
+/// signature, a detached thread, an exception from a constructor or a class or field lookup,
+/// ...) is passed through as [`IntentError::Jni`].
+#[derive(Debug)]
+pub enum IntentError {
+    /// No Activity could be found to handle the intent (`ActivityNotFoundException`).
+    ActivityNotFound(String),
+    /// The caller lacks a permission required by the target (`SecurityException`).
+    SecurityDenied(String),
+    /// Any other Java exception, identified by its class name and message.
+    Other { class: String, message: String },
+    /// A precondition the caller violated on the Rust side, e.g. an empty `uris` slice passed to
+    /// [`Intent::with_clip_data`]. Never produced from a Java exception.
+    InvalidArgument(String),
+    /// A JNI-level failure. This includes [`jni::errors::Error::JavaException`] when the
+    /// exception was thrown outside a Java method call (a constructor, a class or field lookup),
+    /// or when its class name and message could not be read.
+    Jni(JniError),
+}
+
+impl fmt::Display for IntentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ActivityNotFound(message) => {
+                write!(f, "no activity found to handle intent: {message}")
+            }
+            Self::SecurityDenied(message) => write!(f, "security exception: {message}"),
+            Self::Other { class, message } => write!(f, "{class}: {message}"),
+            Self::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+            Self::Jni(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for IntentError {}
+
+impl From<JniError> for IntentError {
+    fn from(error: JniError) -> Self {
+        Self::Jni(error)
+    }
+}
+
+/// `jni::errors::Result`, but with Java exceptions translated into [`IntentError`] by
+/// [`call_method`]/[`call_static_method`] instead of left pending as an opaque `JavaException`.
+pub type Result<T> = std::result::Result<T, IntentError>;
+
+fn call_method<'env>(
+    env: &JNIEnv<'env>,
+    obj: impl Into<JObject<'env>>,
+    name: &str,
+    sig: &str,
+    args: &[JValue<'env>],
+) -> Result<JValue<'env>> {
+    translate_exception(env, env.call_method(obj, name, sig, args))
+}
+
+fn call_static_method<'env>(
+    env: &JNIEnv<'env>,
+    class: jni::objects::JClass<'env>,
+    name: &str,
+    sig: &str,
+    args: &[JValue<'env>],
+) -> Result<JValue<'env>> {
+    translate_exception(env, env.call_static_method(class, name, sig, args))
+}
+
+fn translate_exception<'env, T>(env: &JNIEnv<'env>, result: jni::errors::Result<T>) -> Result<T> {
+    result.map_err(|error| match error {
+        JniError::JavaException => describe_exception(env).unwrap_or(IntentError::Jni(error)),
+        error => IntentError::Jni(error),
+    })
+}
+
+/// Extracts the class name and message of the Java exception currently pending on `env`, then
+/// clears it so subsequent JNI calls are not aborted by the still-pending exception.
+fn describe_exception(env: &JNIEnv) -> Result<IntentError> {
+    let throwable = env.exception_occurred()?;
+    env.exception_clear()?;
+
+    let class = env.call_method(throwable, "getClass", "()Ljava/lang/Class;", &[])?;
+    let class: JObject = class.l()?;
+    let class_name = env.call_method(class, "getName", "()Ljava/lang/String;", &[])?;
+    let class_name: JObject = class_name.l()?;
+    let class_name: String = env.get_string(class_name.into())?.into();
+
+    let message = env.call_method(throwable, "getMessage", "()Ljava/lang/String;", &[])?;
+    let message: JObject = message.l()?;
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        env.get_string(message.into())?.into()
+    };
+
+    Ok(match class_name.as_str() {
+        "android.content.ActivityNotFoundException" => IntentError::ActivityNotFound(message),
+        "java.lang.SecurityException" => IntentError::SecurityDenied(message),
+        _ => IntentError::Other {
+            class: class_name,
+            message,
+        },
+    })
+}
 
 /// A messaging object you can use to request an action from another android app component.
 pub struct Intent<'env> {
@@ -31,7 +142,8 @@ impl<'env> Intent<'env> {
     ) -> Result<Self> {
         let url_string = env.new_string(uri)?;
         let uri_class = env.find_class("android/net/Uri")?;
-        let uri = env.call_static_method(
+        let uri = call_static_method(
+            &env,
             uri_class,
             "parse",
             "(Ljava/lang/String;)Landroid/net/Uri;",
@@ -71,7 +183,8 @@ impl<'env> Intent<'env> {
         let package_name = self.env.new_string(package_name)?;
         let class_name = self.env.new_string(class_name)?;
 
-        self.env.call_method(
+        call_method(
+            &self.env,
             self.object,
             "setClassName",
             "(Ljava/lang/String;Ljava/lang/String;)Landroid/content/Intent;",
@@ -81,6 +194,41 @@ impl<'env> Intent<'env> {
         Ok(self)
     }
 
+    /// Set an explicit `ComponentName` target for the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_component("com.example", "IntentTarget").unwrap();
+    /// # })
+    /// ```
+    pub fn with_component(
+        self,
+        package_name: impl AsRef<str>,
+        class_name: impl AsRef<str>,
+    ) -> Result<Self> {
+        let package_name = self.env.new_string(package_name)?;
+        let class_name = self.env.new_string(class_name)?;
+
+        let component_class = self.env.find_class("android/content/ComponentName")?;
+        let component = self.env.new_object(
+            component_class,
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+            &[package_name.into(), class_name.into()],
+        )?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "setComponent",
+            "(Landroid/content/ComponentName;)Landroid/content/Intent;",
+            &[component.into()],
+        )?;
+
+        Ok(self)
+    }
+
     /// Add extended data to the intent.
     /// ```no_run
     /// use android_intent::{Action, Extra, Intent};
@@ -94,7 +242,8 @@ impl<'env> Intent<'env> {
         let key = self.env.new_string(key)?;
         let value = self.env.new_string(value)?;
 
-        self.env.call_method(
+        call_method(
+            &self.env,
             self.object,
             "putExtra",
             "(Ljava/lang/String;Ljava/lang/String;)Landroid/content/Intent;",
@@ -104,6 +253,160 @@ impl<'env> Intent<'env> {
         Ok(self)
     }
 
+    /// Add a 32-bit integer extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_i32("count", 42).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_i32(self, key: impl AsRef<str>, value: i32) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;I)Landroid/content/Intent;",
+            &[key.into(), value.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a 64-bit integer extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_i64("timestamp", 42).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_i64(self, key: impl AsRef<str>, value: i64) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;J)Landroid/content/Intent;",
+            &[key.into(), value.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a boolean extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_bool("enabled", true).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_bool(self, key: impl AsRef<str>, value: bool) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;Z)Landroid/content/Intent;",
+            &[key.into(), value.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a double-precision floating point extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_f64("rating", 4.5).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_f64(self, key: impl AsRef<str>, value: f64) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;D)Landroid/content/Intent;",
+            &[key.into(), value.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a byte array extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_bytes("payload", &[1, 2, 3]).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_bytes(self, key: impl AsRef<str>, value: &[u8]) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+        let array = self.env.new_byte_array(value.len() as i32)?;
+        let signed: Vec<i8> = value.iter().map(|&b| b as i8).collect();
+        self.env.set_byte_array_region(array, 0, &signed)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;[B)Landroid/content/Intent;",
+            &[key.into(), JObject::from(array).into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a string array extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_extra_string_array("recipients", &["a@example.com", "b@example.com"]).unwrap();
+    /// # })
+    /// ```
+    pub fn with_extra_string_array(
+        self,
+        key: impl AsRef<str>,
+        value: &[impl AsRef<str>],
+    ) -> Result<Self> {
+        let key = self.env.new_string(key)?;
+        let string_class = self.env.find_class("java/lang/String")?;
+        let array = self
+            .env
+            .new_object_array(value.len() as i32, string_class, JObject::null())?;
+        for (i, item) in value.iter().enumerate() {
+            let jstring = self.env.new_string(item)?;
+            self.env
+                .set_object_array_element(array, i as i32, jstring)?;
+        }
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;[Ljava/lang/String;)Landroid/content/Intent;",
+            &[key.into(), JObject::from(array).into()],
+        )?;
+
+        Ok(self)
+    }
+
     /// Builds a new [`super::Action::Chooser`] Intent that wraps the given target intent.
     /// ```no_run
     /// use android_intent::{Action, Intent};
@@ -128,7 +431,8 @@ impl<'env> Intent<'env> {
         };
 
         let intent_class = self.env.find_class("android/content/Intent")?;
-        let intent = self.env.call_static_method(
+        let intent = call_static_method(
+            &self.env,
             intent_class,
             "createChooser",
             "(Landroid/content/Intent;Ljava/lang/CharSequence;)Landroid/content/Intent;",
@@ -151,7 +455,8 @@ impl<'env> Intent<'env> {
     pub fn with_type(self, type_name: impl AsRef<str>) -> Result<Self> {
         let jstring = self.env.new_string(type_name)?;
 
-        self.env.call_method(
+        call_method(
+            &self.env,
             self.object,
             "setType",
             "(Ljava/lang/String;)Landroid/content/Intent;",
@@ -161,11 +466,195 @@ impl<'env> Intent<'env> {
         Ok(self)
     }
 
+    /// Attach a single stream to share, via `EXTRA_STREAM` holding a parceled `Uri`. Pairs with
+    /// [`Action::Send`](super::Action::Send).
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::Send).unwrap()
+    ///     .with_stream_uri("content://com.example/file.png").unwrap();
+    /// # })
+    /// ```
+    pub fn with_stream_uri(self, uri: impl AsRef<str>) -> Result<Self> {
+        let key = self.env.new_string("android.intent.extra.STREAM")?;
+        let uri = self.parse_uri(uri)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putExtra",
+            "(Ljava/lang/String;Landroid/os/Parcelable;)Landroid/content/Intent;",
+            &[key.into(), uri.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Attach multiple streams to share, via `EXTRA_STREAM` holding a parceled `ArrayList<Uri>`.
+    /// Pairs with [`Action::SendMultiple`](super::Action::SendMultiple).
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::SendMultiple).unwrap()
+    ///     .with_stream_uris(&["content://com.example/a.png", "content://com.example/b.png"]).unwrap();
+    /// # })
+    /// ```
+    pub fn with_stream_uris(self, uris: &[impl AsRef<str>]) -> Result<Self> {
+        let key = self.env.new_string("android.intent.extra.STREAM")?;
+        let uri_list = self.new_uri_array_list(uris)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "putParcelableArrayListExtra",
+            "(Ljava/lang/String;Ljava/util/ArrayList;)Landroid/content/Intent;",
+            &[key.into(), uri_list.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Attach a `ClipData` holding `uris`, via `setClipData`. Combine with
+    /// [`Flags::GRANT_READ_URI_PERMISSION`] so every target app can read each attached `Uri`,
+    /// which `EXTRA_STREAM` alone does not grant for `ACTION_SEND_MULTIPLE`.
+    /// ```no_run
+    /// use android_intent::{Action, Flags, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::SendMultiple).unwrap()
+    ///     .with_clip_data(&["content://com.example/a.png", "content://com.example/b.png"]).unwrap()
+    ///     .with_flags(Flags::GRANT_READ_URI_PERMISSION).unwrap();
+    /// # })
+    /// ```
+    pub fn with_clip_data(self, uris: &[impl AsRef<str>]) -> Result<Self> {
+        let Some((first, rest)) = uris.split_first() else {
+            return Err(IntentError::InvalidArgument(
+                "with_clip_data requires at least one uri".to_owned(),
+            ));
+        };
+
+        let first_uri = self.parse_uri(first)?;
+        let clip_data_class = self.env.find_class("android/content/ClipData")?;
+        let clip_data = call_static_method(
+            &self.env,
+            clip_data_class,
+            "newRawUri",
+            "(Ljava/lang/CharSequence;Landroid/net/Uri;)Landroid/content/ClipData;",
+            &[JObject::null().into(), first_uri.into()],
+        )?;
+        let clip_data: JObject = clip_data.try_into()?;
+
+        let item_class = self.env.find_class("android/content/ClipData$Item")?;
+        for uri in rest {
+            let uri = self.parse_uri(uri)?;
+            let item = self
+                .env
+                .new_object(item_class, "(Landroid/net/Uri;)V", &[uri.into()])?;
+
+            call_method(
+                &self.env,
+                clip_data,
+                "addItem",
+                "(Landroid/content/ClipData$Item;)V",
+                &[item.into()],
+            )?;
+        }
+
+        call_method(
+            &self.env,
+            self.object,
+            "setClipData",
+            "(Landroid/content/ClipData;)Landroid/content/Intent;",
+            &[clip_data.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    fn parse_uri(&self, uri: impl AsRef<str>) -> Result<JObject<'env>> {
+        let uri_string = self.env.new_string(uri)?;
+        let uri_class = self.env.find_class("android/net/Uri")?;
+
+        Ok(call_static_method(
+            &self.env,
+            uri_class,
+            "parse",
+            "(Ljava/lang/String;)Landroid/net/Uri;",
+            &[uri_string.into()],
+        )?
+        .l()?)
+    }
+
+    fn new_uri_array_list(&self, uris: &[impl AsRef<str>]) -> Result<JObject<'env>> {
+        let array_list_class = self.env.find_class("java/util/ArrayList")?;
+        let array_list = self.env.new_object(array_list_class, "()V", &[])?;
+
+        for uri in uris {
+            let uri = self.parse_uri(uri)?;
+            call_method(
+                &self.env,
+                array_list,
+                "add",
+                "(Ljava/lang/Object;)Z",
+                &[uri.into()],
+            )?;
+        }
+
+        Ok(array_list)
+    }
+
+    /// Add additional flags to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Flags, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::View).unwrap()
+    ///     .with_flags(Flags::ACTIVITY_NEW_TASK).unwrap();
+    /// # })
+    /// ```
+    pub fn with_flags(self, flags: i32) -> Result<Self> {
+        call_method(
+            &self.env,
+            self.object,
+            "addFlags",
+            "(I)Landroid/content/Intent;",
+            &[flags.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Add a category to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = Intent::new(env, Action::View).unwrap()
+    ///     .add_category("android.intent.category.DEFAULT").unwrap();
+    /// # })
+    /// ```
+    pub fn add_category(self, category: impl AsRef<str>) -> Result<Self> {
+        let category = self.env.new_string(category)?;
+
+        call_method(
+            &self.env,
+            self.object,
+            "addCategory",
+            "(Ljava/lang/String;)Landroid/content/Intent;",
+            &[category.into()],
+        )?;
+
+        Ok(self)
+    }
+
     pub fn start_activity(self) -> Result<()> {
         let cx = ndk_context::android_context();
         let activity = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
 
-        self.env.call_method(
+        call_method(
+            &self.env,
             activity,
             "startActivity",
             "(Landroid/content/Intent;)V",
@@ -174,6 +663,301 @@ impl<'env> Intent<'env> {
 
         Ok(())
     }
+
+    /// Starts the activity, first adding [`Flags::ACTIVITY_NEW_TASK`] so it succeeds even
+    /// when the current context (e.g. an Application or Service) is not itself an Activity.
+    /// ```no_run
+    /// use android_intent::{Action, Intent};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// Intent::new(env, Action::View).unwrap()
+    ///     .start_activity_new_task().unwrap();
+    /// # })
+    /// ```
+    pub fn start_activity_new_task(self) -> Result<()> {
+        self.with_flags(Flags::ACTIVITY_NEW_TASK)?.start_activity()
+    }
+
+    /// Starts the activity for a result, delivered back to the calling Activity's
+    /// `onActivityResult(int, int, Intent)` tagged with `request_code`. Parse the callback's
+    /// `result_code` and data `Intent` with [`ActivityResult::from_object`].
+    pub fn start_activity_for_result(self, request_code: i32) -> Result<()> {
+        let cx = ndk_context::android_context();
+        let activity = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        call_method(
+            &self.env,
+            activity,
+            "startActivityForResult",
+            "(Landroid/content/Intent;I)V",
+            &[self.object.into(), request_code.into()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Starts the intent's target as a background service, via `Context.startService`.
+    pub fn start_service(self) -> Result<()> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        call_method(
+            &self.env,
+            context,
+            "startService",
+            "(Landroid/content/Intent;)Landroid/content/ComponentName;",
+            &[self.object.into()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Starts the intent's target as a foreground service, via `Context.startForegroundService`
+    /// (API 26+). The service must promote itself with `startForeground` shortly after.
+    pub fn start_foreground_service(self) -> Result<()> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        call_method(
+            &self.env,
+            context,
+            "startForegroundService",
+            "(Landroid/content/Intent;)Landroid/content/ComponentName;",
+            &[self.object.into()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Stops the intent's target service, via `Context.stopService`.
+    pub fn stop_service(self) -> Result<()> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        call_method(
+            &self.env,
+            context,
+            "stopService",
+            "(Landroid/content/Intent;)Z",
+            &[self.object.into()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Checks whether any Activity on the device can handle this intent, via
+    /// `Intent.resolveActivity(PackageManager)`. Lets callers fall back gracefully instead of
+    /// crashing with `ActivityNotFoundException` when nothing matches, e.g. an unhandled
+    /// `ACTION_VIEW` URI.
+    pub fn resolve_activity(&self) -> Result<bool> {
+        let package_manager = self.package_manager()?;
+
+        let component_name = call_method(
+            &self.env,
+            self.object,
+            "resolveActivity",
+            "(Landroid/content/pm/PackageManager;)Landroid/content/ComponentName;",
+            &[package_manager.into()],
+        )?;
+        let component_name: JObject = component_name.try_into()?;
+
+        Ok(!component_name.is_null())
+    }
+
+    /// Lists the package names of every Activity that can handle this intent, via
+    /// `PackageManager.queryIntentActivities`.
+    pub fn query_activities(&self) -> Result<Vec<String>> {
+        let package_manager = self.package_manager()?;
+
+        let resolve_infos = call_method(
+            &self.env,
+            package_manager,
+            "queryIntentActivities",
+            "(Landroid/content/Intent;I)Ljava/util/List;",
+            &[self.object.into(), 0i32.into()],
+        )?;
+        let resolve_infos: JObject = resolve_infos.try_into()?;
+
+        let count = call_method(&self.env, resolve_infos, "size", "()I", &[])?.i()?;
+
+        let mut package_names = Vec::with_capacity(count as usize);
+        for i in 0..count {
+            let resolve_info = call_method(
+                &self.env,
+                resolve_infos,
+                "get",
+                "(I)Ljava/lang/Object;",
+                &[i.into()],
+            )?
+            .l()?;
+            let activity_info = self
+                .env
+                .get_field(
+                    resolve_info,
+                    "activityInfo",
+                    "Landroid/content/pm/ActivityInfo;",
+                )?
+                .l()?;
+            let package_name = self
+                .env
+                .get_field(activity_info, "packageName", "Ljava/lang/String;")?
+                .l()?;
+
+            package_names.push(self.env.get_string(package_name.into())?.into());
+
+            self.env.delete_local_ref(package_name)?;
+            self.env.delete_local_ref(activity_info)?;
+            self.env.delete_local_ref(resolve_info)?;
+        }
+
+        Ok(package_names)
+    }
+
+    fn package_manager(&self) -> Result<JObject<'env>> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        Ok(call_method(
+            &self.env,
+            context,
+            "getPackageManager",
+            "()Landroid/content/pm/PackageManager;",
+            &[],
+        )?
+        .l()?)
+    }
+}
+
+/// Flag constants for [`Intent::with_flags`], mirroring `android.content.Intent`'s `FLAG_ACTIVITY_*`
+/// and `FLAG_GRANT_*` fields.
+pub struct Flags;
+
+impl Flags {
+    /// `Intent.FLAG_ACTIVITY_NEW_TASK`. Required when starting an Activity from a context
+    /// (Application, Service) that is not itself an Activity.
+    pub const ACTIVITY_NEW_TASK: i32 = 0x10000000;
+    /// `Intent.FLAG_ACTIVITY_CLEAR_TOP`.
+    pub const ACTIVITY_CLEAR_TOP: i32 = 0x04000000;
+    /// `Intent.FLAG_GRANT_READ_URI_PERMISSION`.
+    pub const GRANT_READ_URI_PERMISSION: i32 = 0x00000001;
+    /// `Intent.FLAG_GRANT_WRITE_URI_PERMISSION`.
+    pub const GRANT_WRITE_URI_PERMISSION: i32 = 0x00000002;
+}
+
+/// The `(result_code, data)` pair delivered to an Activity's
+/// `onActivityResult(int, int, Intent)` after [`Intent::start_activity_for_result`]. `data`
+/// is `None` when the target Activity finished without ever filling in a result Intent, e.g.
+/// `RESULT_CANCELED` when the user backs out of a picker.
+pub struct ActivityResult<'env> {
+    env: JNIEnv<'env>,
+    result_code: i32,
+    data: Option<JObject<'env>>,
+}
+
+impl<'env> ActivityResult<'env> {
+    /// Wraps the `result_code` and data `Intent` passed to `onActivityResult`.
+    pub fn from_object(env: JNIEnv<'env>, result_code: i32, data: JObject<'env>) -> Self {
+        Self {
+            env,
+            result_code,
+            data: if data.is_null() { None } else { Some(data) },
+        }
+    }
+
+    /// The `resultCode` the target Activity finished with, e.g. `Activity.RESULT_OK`.
+    pub fn result_code(&self) -> i32 {
+        self.result_code
+    }
+
+    /// Reads a string extra from the result data, via `Intent.getStringExtra`. Returns `None`
+    /// if there is no result data at all, e.g. `RESULT_CANCELED`.
+    pub fn get_string_extra(&self, key: impl AsRef<str>) -> Result<Option<String>> {
+        let Some(data) = self.data else {
+            return Ok(None);
+        };
+        let key = self.env.new_string(key)?;
+
+        let value = call_method(
+            &self.env,
+            data,
+            "getStringExtra",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+            &[key.into()],
+        )?;
+        let value: JObject = value.try_into()?;
+
+        if value.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(self.env.get_string(value.into())?.into()))
+        }
+    }
+
+    /// Reads an integer extra from the result data, via `Intent.getIntExtra`. Returns
+    /// `default_value` if there is no result data at all, e.g. `RESULT_CANCELED`.
+    pub fn get_int_extra(&self, key: impl AsRef<str>, default_value: i32) -> Result<i32> {
+        let Some(data) = self.data else {
+            return Ok(default_value);
+        };
+        let key = self.env.new_string(key)?;
+
+        let value = call_method(
+            &self.env,
+            data,
+            "getIntExtra",
+            "(Ljava/lang/String;I)I",
+            &[key.into(), default_value.into()],
+        )?;
+
+        Ok(value.i()?)
+    }
+
+    /// Reads a byte array extra from the result data, via `Intent.getByteArrayExtra`. Returns
+    /// `None` if there is no result data at all, e.g. `RESULT_CANCELED`.
+    pub fn get_bytes_extra(&self, key: impl AsRef<str>) -> Result<Option<Vec<u8>>> {
+        let Some(data) = self.data else {
+            return Ok(None);
+        };
+        let key = self.env.new_string(key)?;
+
+        let value = call_method(
+            &self.env,
+            data,
+            "getByteArrayExtra",
+            "(Ljava/lang/String;)[B",
+            &[key.into()],
+        )?;
+        let value: JObject = value.try_into()?;
+
+        if value.is_null() {
+            Ok(None)
+        } else {
+            let array = value.into_inner() as jni::sys::jbyteArray;
+            let len = self.env.get_array_length(array)?;
+            let mut buf = vec![0i8; len as usize];
+            self.env.get_byte_array_region(array, 0, &mut buf)?;
+            Ok(Some(buf.into_iter().map(|b| b as u8).collect()))
+        }
+    }
+
+    /// Reads the result data's `Uri` as a string, via `Intent.getData().toString()`. Returns
+    /// `None` if there is no result data at all, e.g. `RESULT_CANCELED`.
+    pub fn get_data(&self) -> Result<Option<String>> {
+        let Some(data) = self.data else {
+            return Ok(None);
+        };
+
+        let uri = call_method(&self.env, data, "getData", "()Landroid/net/Uri;", &[])?;
+        let uri: JObject = uri.try_into()?;
+
+        if uri.is_null() {
+            Ok(None)
+        } else {
+            let uri_string = call_method(&self.env, uri, "toString", "()Ljava/lang/String;", &[])?;
+            let uri_string: JObject = uri_string.try_into()?;
+            Ok(Some(self.env.get_string(uri_string.into())?.into()))
+        }
+    }
 }
 
 /// Builder for intents that allows to capture [`Result`] at the end.
@@ -219,6 +1003,23 @@ impl<'env> IntentBuilder<'env> {
         self.and_then(|inner| inner.with_class_name(package_name, class_name))
     }
 
+    /// Set an explicit `ComponentName` target for the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_component("com.example", "IntentTarget");
+    /// # })
+    /// ```
+    pub fn with_component(
+        self,
+        package_name: impl AsRef<str>,
+        class_name: impl AsRef<str>,
+    ) -> Self {
+        self.and_then(|inner| inner.with_component(package_name, class_name))
+    }
+
     /// Add extended data to the intent.
     /// ```no_run
     /// use android_intent::{Action, Extra, IntentBuilder};
@@ -232,6 +1033,84 @@ impl<'env> IntentBuilder<'env> {
         self.and_then(|inner| inner.with_extra(key, value))
     }
 
+    /// Add a 32-bit integer extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_i32("count", 42);
+    /// # })
+    /// ```
+    pub fn with_extra_i32(self, key: impl AsRef<str>, value: i32) -> Self {
+        self.and_then(|inner| inner.with_extra_i32(key, value))
+    }
+
+    /// Add a 64-bit integer extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_i64("timestamp", 42);
+    /// # })
+    /// ```
+    pub fn with_extra_i64(self, key: impl AsRef<str>, value: i64) -> Self {
+        self.and_then(|inner| inner.with_extra_i64(key, value))
+    }
+
+    /// Add a boolean extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_bool("enabled", true);
+    /// # })
+    /// ```
+    pub fn with_extra_bool(self, key: impl AsRef<str>, value: bool) -> Self {
+        self.and_then(|inner| inner.with_extra_bool(key, value))
+    }
+
+    /// Add a double-precision floating point extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_f64("rating", 4.5);
+    /// # })
+    /// ```
+    pub fn with_extra_f64(self, key: impl AsRef<str>, value: f64) -> Self {
+        self.and_then(|inner| inner.with_extra_f64(key, value))
+    }
+
+    /// Add a byte array extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_bytes("payload", &[1, 2, 3]);
+    /// # })
+    /// ```
+    pub fn with_extra_bytes(self, key: impl AsRef<str>, value: &[u8]) -> Self {
+        self.and_then(|inner| inner.with_extra_bytes(key, value))
+    }
+
+    /// Add a string array extra to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_extra_string_array("recipients", &["a@example.com", "b@example.com"]);
+    /// # })
+    /// ```
+    pub fn with_extra_string_array(self, key: impl AsRef<str>, value: &[impl AsRef<str>]) -> Self {
+        self.and_then(|inner| inner.with_extra_string_array(key, value))
+    }
+
     /// Builds a new [`super::Action::Chooser`] Intent that wraps the given target intent.
     /// ```no_run
     /// use android_intent::{Action, IntentBuilder};
@@ -263,12 +1142,209 @@ impl<'env> IntentBuilder<'env> {
         self.and_then(|inner| inner.with_type(type_name))
     }
 
+    /// Attach a single stream to share, via `EXTRA_STREAM` holding a parceled `Uri`. Pairs with
+    /// [`Action::Send`](super::Action::Send).
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::Send)
+    ///     .with_stream_uri("content://com.example/file.png");
+    /// # })
+    /// ```
+    pub fn with_stream_uri(self, uri: impl AsRef<str>) -> Self {
+        self.and_then(|inner| inner.with_stream_uri(uri))
+    }
+
+    /// Attach multiple streams to share, via `EXTRA_STREAM` holding a parceled `ArrayList<Uri>`.
+    /// Pairs with [`Action::SendMultiple`](super::Action::SendMultiple).
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::SendMultiple)
+    ///     .with_stream_uris(&["content://com.example/a.png", "content://com.example/b.png"]);
+    /// # })
+    /// ```
+    pub fn with_stream_uris(self, uris: &[impl AsRef<str>]) -> Self {
+        self.and_then(|inner| inner.with_stream_uris(uris))
+    }
+
+    /// Attach a `ClipData` holding `uris`, via `setClipData`. Combine with
+    /// [`Flags::GRANT_READ_URI_PERMISSION`] so every target app can read each attached `Uri`.
+    /// ```no_run
+    /// use android_intent::{Action, Flags, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::SendMultiple)
+    ///     .with_clip_data(&["content://com.example/a.png", "content://com.example/b.png"])
+    ///     .with_flags(Flags::GRANT_READ_URI_PERMISSION);
+    /// # })
+    /// ```
+    pub fn with_clip_data(self, uris: &[impl AsRef<str>]) -> Self {
+        self.and_then(|inner| inner.with_clip_data(uris))
+    }
+
+    /// Add additional flags to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, Flags, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::View)
+    ///     .with_flags(Flags::ACTIVITY_NEW_TASK);
+    /// # })
+    /// ```
+    pub fn with_flags(self, flags: i32) -> Self {
+        self.and_then(|inner| inner.with_flags(flags))
+    }
+
+    /// Add a category to the intent.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// let intent = IntentBuilder::new(env, Action::View)
+    ///     .add_category("android.intent.category.DEFAULT");
+    /// # })
+    /// ```
+    pub fn add_category(self, category: impl AsRef<str>) -> Self {
+        self.and_then(|inner| inner.add_category(category))
+    }
+
     pub fn start_activity(self) -> Result<()> {
         self.inner.and_then(|inner| inner.start_activity())
     }
 
+    /// Starts the activity, first adding [`Flags::ACTIVITY_NEW_TASK`] so it succeeds even
+    /// when the current context (e.g. an Application or Service) is not itself an Activity.
+    /// ```no_run
+    /// use android_intent::{Action, IntentBuilder};
+    ///
+    /// # android_intent::with_current_env(|env| {
+    /// IntentBuilder::new(env, Action::View).start_activity_new_task();
+    /// # })
+    /// ```
+    pub fn start_activity_new_task(self) -> Result<()> {
+        self.inner.and_then(|inner| inner.start_activity_new_task())
+    }
+
+    /// Starts the activity for a result, delivered back to the calling Activity's
+    /// `onActivityResult(int, int, Intent)` tagged with `request_code`.
+    pub fn start_activity_for_result(self, request_code: i32) -> Result<()> {
+        self.inner
+            .and_then(|inner| inner.start_activity_for_result(request_code))
+    }
+
+    /// Starts the intent's target as a background service, via `Context.startService`.
+    pub fn start_service(self) -> Result<()> {
+        self.inner.and_then(|inner| inner.start_service())
+    }
+
+    /// Starts the intent's target as a foreground service, via `Context.startForegroundService`
+    /// (API 26+).
+    pub fn start_foreground_service(self) -> Result<()> {
+        self.inner
+            .and_then(|inner| inner.start_foreground_service())
+    }
+
+    /// Stops the intent's target service, via `Context.stopService`.
+    pub fn stop_service(self) -> Result<()> {
+        self.inner.and_then(|inner| inner.stop_service())
+    }
+
+    /// Checks whether any Activity on the device can handle this intent.
+    pub fn resolve_activity(self) -> Result<bool> {
+        self.inner.and_then(|inner| inner.resolve_activity())
+    }
+
+    /// Lists the package names of every Activity that can handle this intent.
+    pub fn query_activities(self) -> Result<Vec<String>> {
+        self.inner.and_then(|inner| inner.query_activities())
+    }
+
     fn and_then(mut self, f: impl FnOnce(Intent) -> Result<Intent>) -> Self {
         self.inner = self.inner.and_then(f);
         self
     }
 }
+
+/// Builder around `android.app.TaskStackBuilder`, used to synthesize the back stack an Activity
+/// should have when launched from outside the app's normal navigation (e.g. a notification or
+/// widget), and to package the result as a [`PendingIntent`](jni::objects::JObject).
+/// ```no_run
+/// use android_intent::{Action, Intent, TaskStack};
+///
+/// # android_intent::with_current_env(|env| {
+/// let intent = Intent::new(env, Action::View).unwrap();
+/// let pending_intent = TaskStack::create(env).unwrap()
+///     .add_next_intent(intent).unwrap()
+///     .get_pending_intent(0, 0).unwrap();
+/// # })
+/// ```
+pub struct TaskStack<'env> {
+    env: JNIEnv<'env>,
+    object: JObject<'env>,
+}
+
+impl<'env> TaskStack<'env> {
+    /// Creates a new `TaskStackBuilder` for the current context, via `TaskStackBuilder.create`.
+    pub fn create(env: JNIEnv<'env>) -> Result<Self> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+        let task_stack_builder_class = env.find_class("android/app/TaskStackBuilder")?;
+        let object = call_static_method(
+            &env,
+            task_stack_builder_class,
+            "create",
+            "(Landroid/content/Context;)Landroid/app/TaskStackBuilder;",
+            &[context.into()],
+        )?;
+
+        Ok(Self {
+            env,
+            object: object.try_into()?,
+        })
+    }
+
+    /// Adds `intent` to the top of the synthesized back stack, via `addNextIntent`.
+    pub fn add_next_intent(self, intent: Intent<'env>) -> Result<Self> {
+        call_method(
+            &self.env,
+            self.object,
+            "addNextIntent",
+            "(Landroid/content/Intent;)Landroid/app/TaskStackBuilder;",
+            &[intent.object.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Adds `intent`'s parent chain (declared via `parentActivityName`/`<meta-data>`) followed by
+    /// `intent` itself, via `addNextIntentWithParentStack`.
+    pub fn add_next_intent_with_parent(self, intent: Intent<'env>) -> Result<Self> {
+        call_method(
+            &self.env,
+            self.object,
+            "addNextIntentWithParentStack",
+            "(Landroid/content/Intent;)Landroid/app/TaskStackBuilder;",
+            &[intent.object.into()],
+        )?;
+
+        Ok(self)
+    }
+
+    /// Obtains a `PendingIntent` that launches the synthesized back stack, via
+    /// `TaskStackBuilder.getPendingIntent`.
+    pub fn get_pending_intent(self, request_code: i32, flags: i32) -> Result<JObject<'env>> {
+        let pending_intent = call_method(
+            &self.env,
+            self.object,
+            "getPendingIntent",
+            "(II)Landroid/app/PendingIntent;",
+            &[request_code.into(), flags.into()],
+        )?;
+
+        Ok(pending_intent.try_into()?)
+    }
+}